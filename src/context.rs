@@ -0,0 +1,41 @@
+//! Explicit precision/rounding control for functions that would otherwise
+//! infer their working precision from the input argument's own scale.
+
+use crate::rounding::RoundingMode;
+
+/// Bundles a target significant-digit count with a [`RoundingMode`],
+/// following the `Context` design used by bigdecimal-rs. Pass one to the
+/// `_with_context` variant of a function to get deterministic precision
+/// instead of whatever the argument's scale happens to imply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Context {
+    /// Target number of significant digits, or `None` to fall back to the
+    /// precision implied by the argument (the behaviour every function had
+    /// before `Context` existed).
+    pub precision: Option<u64>,
+    /// Rounding mode applied to the final result.
+    pub rounding: RoundingMode,
+}
+
+impl Context {
+    /// Builds a context that pins the working precision to `precision`
+    /// significant digits, breaking ties per `rounding`.
+    pub fn new(precision: u64, rounding: RoundingMode) -> Self {
+        Context {
+            precision: Some(precision),
+            rounding,
+        }
+    }
+}
+
+impl Default for Context {
+    /// Precision is derived from the argument, and ties round half up --
+    /// matching the behavior of every function's original, context-free
+    /// signature.
+    fn default() -> Self {
+        Context {
+            precision: None,
+            rounding: RoundingMode::HalfUp,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 use bigdecimal::BigDecimal;
 
 pub type BigDecimalMathResult = Result<BigDecimal, BigDecimalMathError>;
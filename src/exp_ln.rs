@@ -0,0 +1,157 @@
+//! Natural exponential and logarithm, built the same way as [`crate::pi`] and
+//! [`crate::trig`]: range-reduce into a well-conditioned interval, sum a
+//! Taylor series there, then undo the reduction.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::context::Context;
+use crate::error::{BigDecimalMathError, BigDecimalMathResult};
+use crate::{get_prec, round_result, GUARD_DIGITS};
+use bigdecimal::{BigDecimal, FromPrimitive, One, Zero};
+use num_bigint::BigInt;
+
+/// Computes `e^x` by halving `x` until it is smaller than `1` in magnitude,
+/// summing `Σ x^m/m!` there, then squaring the result back `x` was halved.
+pub fn exp(x: &BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    let working_scale = ctx.precision.unwrap_or(get_prec(x) as u64) as i64 + GUARD_DIGITS;
+    let two = BigDecimal::from(2);
+
+    let mut reduced = x.clone();
+    let mut halvings = 0u32;
+    while reduced.abs() > BigDecimal::one() {
+        reduced = (&reduced / &two).with_scale(working_scale);
+        halvings += 1;
+    }
+
+    let mut value = taylor_exp(&reduced, working_scale);
+    for _ in 0..halvings {
+        value = (&value * &value).with_scale(working_scale);
+    }
+
+    round_result(value, ctx)
+}
+
+/// Computes `ln(x)` (`x > 0`) by repeatedly taking square roots until `x` is
+/// close to `1`, summing `Σ (-1)^(m+1) y^m/m` for `y = x - 1` there, then
+/// scaling the result back up by the number of square roots taken.
+pub fn ln(x: &BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    if x <= &BigDecimal::zero() {
+        let msg = format!("non-positive argument {:?} of ln", x);
+        return Err(BigDecimalMathError::ArithmeticError(msg));
+    }
+
+    let working_scale = ctx.precision.unwrap_or(get_prec(x) as u64) as i64 + GUARD_DIGITS;
+    let sqrt_ctx = Context::new(working_scale as u64, ctx.rounding);
+
+    let mut reduced = x.clone();
+    let mut square_roots = 0u32;
+    let lower = BigDecimal::from_f64(0.8).unwrap();
+    let upper = BigDecimal::from_f64(1.25).unwrap();
+    while reduced < lower || reduced > upper {
+        reduced = crate::root_with_context(2, reduced, &sqrt_ctx)?;
+        square_roots += 1;
+    }
+
+    let ln_reduced = taylor_ln(&reduced, working_scale);
+
+    let mut value = ln_reduced;
+    let two = BigDecimal::from(2);
+    for _ in 0..square_roots {
+        value = (&value * &two).with_scale(working_scale);
+    }
+
+    round_result(value, ctx)
+}
+
+fn taylor_exp(x: &BigDecimal, working_scale: i64) -> BigDecimal {
+    let threshold = BigDecimal::new(BigInt::one(), working_scale);
+
+    let mut sum = BigDecimal::one();
+    let mut term = BigDecimal::one();
+    let mut m: i64 = 1;
+
+    loop {
+        term = (&term * x / m).with_scale(working_scale);
+        sum += &term;
+
+        if term.abs() < threshold {
+            break;
+        }
+
+        m += 1;
+    }
+
+    sum
+}
+
+fn taylor_ln(reduced: &BigDecimal, working_scale: i64) -> BigDecimal {
+    let y = reduced - BigDecimal::one();
+    let threshold = BigDecimal::new(BigInt::one(), working_scale);
+
+    let mut sum = BigDecimal::zero();
+    let mut power = y.clone();
+    let mut m: i64 = 1;
+
+    loop {
+        let term = (&power / m).with_scale(working_scale);
+
+        if m % 2 == 1 {
+            sum += &term;
+        } else {
+            sum -= &term;
+        }
+
+        if term.abs() < threshold {
+            break;
+        }
+
+        power = (&power * &y).with_scale(working_scale);
+        m += 1;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rounding::RoundingMode;
+    use std::str::FromStr;
+
+    #[test]
+    fn exp_of_zero_and_one() {
+        let ctx = Context::new(15, RoundingMode::HalfUp);
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("1.00000000000000").unwrap()),
+            exp(&BigDecimal::zero(), &ctx)
+        );
+        assert_eq!(
+            Ok(BigDecimal::from_str("2.71828182845905").unwrap()),
+            exp(&BigDecimal::one(), &ctx)
+        );
+    }
+
+    #[test]
+    fn ln_of_one_and_e() {
+        let ctx = Context::new(15, RoundingMode::HalfUp);
+        let e = exp(&BigDecimal::one(), &ctx).unwrap();
+
+        assert_eq!(
+            Ok(BigDecimal::zero().with_scale(14)),
+            ln(&BigDecimal::one(), &ctx)
+        );
+        assert_eq!(
+            Ok(BigDecimal::from_str("1.00000000000000").unwrap()),
+            ln(&e, &ctx)
+        );
+    }
+
+    #[test]
+    fn ln_rejects_non_positive_argument() {
+        let ctx = Context::default();
+
+        assert!(ln(&BigDecimal::zero(), &ctx).is_err());
+    }
+}
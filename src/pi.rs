@@ -0,0 +1,112 @@
+//! Computes π to an arbitrary number of significant digits using Machin's
+//! rapidly converging arctan formula.
+
+use crate::context::Context;
+use crate::error::BigDecimalMathResult;
+use bigdecimal::{BigDecimal, FromPrimitive, One, Zero};
+use num_bigint::BigInt;
+
+#[cfg(feature = "std")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use crate::GUARD_DIGITS;
+
+const DEFAULT_PRECISION: u64 = 25;
+
+/// Computes π using `π/4 = 4·arctan(1/5) − arctan(1/239)`, rounded to
+/// `ctx.precision` significant digits (or a sensible default when
+/// `ctx.precision` is `None`). With the `std` feature, repeated calls at the
+/// same precision reuse a cached value instead of re-running the series;
+/// without it, every call re-runs the series.
+pub fn pi(ctx: &Context) -> BigDecimalMathResult {
+    let precision = ctx.precision.unwrap_or(DEFAULT_PRECISION);
+    let raw = cached_pi(precision)?;
+
+    // pi always has exactly one integer digit ("3"), so `precision`
+    // significant digits means `precision - 1` digits after the point.
+    crate::rounding::round(&raw, precision as i64 - 1, ctx.rounding)
+}
+
+#[cfg(feature = "std")]
+fn cached_pi(precision: u64) -> BigDecimalMathResult {
+    if let Some(hit) = PI_CACHE.lock().unwrap().get(&precision) {
+        return Ok(hit.clone());
+    }
+
+    let value = compute_pi(precision)?;
+    PI_CACHE.lock().unwrap().insert(precision, value.clone());
+    Ok(value)
+}
+
+#[cfg(not(feature = "std"))]
+fn cached_pi(precision: u64) -> BigDecimalMathResult {
+    compute_pi(precision)
+}
+
+#[cfg(feature = "std")]
+static PI_CACHE: Lazy<Mutex<HashMap<u64, BigDecimal>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn compute_pi(precision: u64) -> BigDecimalMathResult {
+    let working_scale = precision as i64 + GUARD_DIGITS;
+
+    let arctan_5 = arctan_reciprocal(5, working_scale);
+    let arctan_239 = arctan_reciprocal(239, working_scale);
+    let four = BigDecimal::from_i64(4).unwrap();
+
+    let pi_over_4 = &four * arctan_5 - arctan_239;
+
+    Ok((&four * pi_over_4).with_scale(working_scale))
+}
+
+/// Sums `arctan(1/k) = Σ (-1)^m / ((2m+1)·k^(2m+1))` until a term drops below
+/// `10^-working_scale`.
+fn arctan_reciprocal(k: i64, working_scale: i64) -> BigDecimal {
+    let k_bd = BigDecimal::from_i64(k).unwrap();
+    let k_squared = &k_bd * &k_bd;
+    let threshold = BigDecimal::new(BigInt::one(), working_scale);
+
+    let mut sum = BigDecimal::zero();
+    let mut power = (BigDecimal::one() / &k_bd).with_scale(working_scale);
+    let mut m: i64 = 0;
+
+    loop {
+        let denominator = BigDecimal::from_i64(2 * m + 1).unwrap();
+        let term = (&power / &denominator).with_scale(working_scale);
+
+        if m % 2 == 0 {
+            sum += &term;
+        } else {
+            sum -= &term;
+        }
+
+        if term.abs() < threshold {
+            break;
+        }
+
+        power = (&power / &k_squared).with_scale(working_scale);
+        m += 1;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rounding::RoundingMode;
+    use std::str::FromStr;
+
+    #[test]
+    fn pi_to_twenty_digits() {
+        let ctx = Context::new(20, RoundingMode::HalfUp);
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("3.1415926535897932385").unwrap()),
+            pi(&ctx)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! The general real-valued power operation `base^exponent`, for exponents
+//! that need not be non-negative integers.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::context::Context;
+use crate::error::{BigDecimalMathError, BigDecimalMathResult};
+use crate::exp_ln::{exp, ln};
+use bigdecimal::{BigDecimal, One, ToPrimitive, Zero};
+use num_bigint::Sign;
+
+/// Computes `base^exponent`, rounding to `ctx.precision` significant digits.
+///
+/// Integer exponents dispatch to the fast integer power path (negative
+/// integers via the reciprocal), exponents whose reciprocal is an integer
+/// dispatch to [`crate::root_with_context`] (for a non-negative `base`), and
+/// everything else falls back to `exp(exponent * ln(base))`. A negative
+/// `base` with any non-integer exponent, including one whose reciprocal is
+/// an integer, is an error.
+pub fn pow(base: BigDecimal, exponent: BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    if exponent.is_integer() {
+        return pow_int(base, &exponent).and_then(|value| crate::round_result(value, ctx));
+    }
+
+    if base.sign() != Sign::Minus {
+        let reciprocal = BigDecimal::one() / &exponent;
+        if reciprocal.is_integer() {
+            if let Some(n) = reciprocal.to_isize() {
+                return crate::root_with_context(n, base, ctx);
+            }
+        }
+    }
+
+    if base.sign() == Sign::Minus {
+        let msg = format!(
+            "negative base {:?} with non-integer exponent {:?} has no real result",
+            base, exponent
+        );
+        return Err(BigDecimalMathError::ArithmeticError(msg));
+    }
+
+    exp(&(&exponent * ln(&base, ctx)?), ctx)
+}
+
+fn pow_int(base: BigDecimal, exponent: &BigDecimal) -> BigDecimalMathResult {
+    let n = exponent.to_i32().ok_or_else(|| {
+        BigDecimalMathError::ArithmeticError(format!("exponent {:?} out of range", exponent))
+    })?;
+
+    if n >= 0 {
+        crate::int_pow(&base, n)
+    } else {
+        let reciprocal_base = crate::int_pow(&base, -n)?;
+        if reciprocal_base.is_zero() {
+            let msg = format!("cannot raise {:?} to the negative power {}", base, n);
+            return Err(BigDecimalMathError::ArithmeticError(msg));
+        }
+        Ok(BigDecimal::one() / reciprocal_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rounding::RoundingMode;
+    use std::str::FromStr;
+
+    #[test]
+    fn integer_exponents() {
+        let ctx = Context::default();
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("9").unwrap()),
+            pow(BigDecimal::from_str("3").unwrap(), BigDecimal::from_str("2").unwrap(), &ctx)
+        );
+        assert_eq!(
+            Ok(BigDecimal::from_str("0.25").unwrap()),
+            pow(BigDecimal::from_str("2").unwrap(), BigDecimal::from_str("-2").unwrap(), &ctx)
+        );
+    }
+
+    #[test]
+    fn exact_root_exponent() {
+        let ctx = Context::new(10, RoundingMode::HalfUp);
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("3").unwrap()),
+            pow(
+                BigDecimal::from_str("9").unwrap(),
+                BigDecimal::from_str("0.5").unwrap(),
+                &ctx
+            )
+        );
+    }
+
+    #[test]
+    fn negative_base_with_fractional_exponent_is_an_error() {
+        let ctx = Context::default();
+
+        assert!(pow(
+            BigDecimal::from_str("-9").unwrap(),
+            BigDecimal::from_str("0.3").unwrap(),
+            &ctx
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Arbitrary-precision rounding, mirroring the relevant slice of Java's
+//! `BigDecimal`/`BigInteger` internals (`setScale`, `divideAndRound`,
+//! `compareHalf`) that the rest of the crate rounds results through.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::error::{BigDecimalMathError, BigDecimalMathResult};
+use bigdecimal::{BigDecimal, One, Zero};
+use core::convert::TryFrom;
+use num_bigint::{BigInt, Sign, ToBigInt};
+
+/// Mirrors `java.math.RoundingMode`: how a discarded fraction is folded back
+/// into the retained digits when rounding to a target scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round towards zero (truncate).
+    Down,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards the nearest neighbour, ties round away from zero.
+    HalfUp,
+    /// Round towards the nearest neighbour, ties round towards zero.
+    HalfDown,
+    /// Round towards the nearest neighbour, ties round towards the even neighbour.
+    HalfEven,
+}
+
+/// Rounds `x` to `scale` digits after the decimal point, using `mode` to
+/// decide how the discarded remainder is folded back in. Mirrors Java's
+/// `BigDecimal.setScale(scale, roundingMode)`.
+pub fn round(x: &BigDecimal, scale: i64, mode: RoundingMode) -> BigDecimalMathResult {
+    do_round(x, scale, mode)
+}
+
+fn do_round(x: &BigDecimal, scale: i64, mode: RoundingMode) -> BigDecimalMathResult {
+    let (int_val, current_scale) = x.as_bigint_and_exponent();
+
+    if scale >= current_scale {
+        // Increasing (or keeping) the scale never loses digits, so there's
+        // nothing to round -- just pad the unscaled value with zeros.
+        return Ok(x.with_scale(scale));
+    }
+
+    let digits_to_drop = check_scale_non_zero(current_scale - scale)?;
+    let rounded = divide_and_round_by_ten_pow(int_val, digits_to_drop, mode);
+
+    Ok(BigDecimal::new(rounded, scale))
+}
+
+fn check_scale_non_zero(val: i64) -> Result<i32, BigDecimalMathError> {
+    i32::try_from(val)
+        .map_err(|_| BigDecimalMathError::ArithmeticError(format!("scale out of range: {}", val)))
+}
+
+fn divide_and_round_by_ten_pow(int_val: BigInt, ten_pow: i32, mode: RoundingMode) -> BigInt {
+    if (ten_pow as usize) < LONG_TEN_POWERS_TABLE.len() {
+        divide_and_round_i64(int_val, LONG_TEN_POWERS_TABLE[ten_pow as usize], mode)
+    } else {
+        divide_and_round_bigint(int_val, big_ten_to_the(ten_pow), mode)
+    }
+}
+
+fn divide_and_round_i64(int_val: BigInt, divisor: i64, mode: RoundingMode) -> BigInt {
+    divide_and_round_bigint(int_val, BigInt::from(divisor), mode)
+}
+
+fn divide_and_round_bigint(dividend: BigInt, divisor: BigInt, mode: RoundingMode) -> BigInt {
+    let quotient = &dividend / &divisor;
+    let remainder = &dividend - &quotient * &divisor;
+
+    if needs_increment(&remainder, &divisor, &quotient, mode) {
+        match remainder.sign() {
+            Sign::Minus => quotient - BigInt::one(),
+            _ => quotient + BigInt::one(),
+        }
+    } else {
+        quotient
+    }
+}
+
+/// Decides whether `quotient` (with discarded remainder `remainder`, over
+/// `divisor`) needs to be bumped away from zero, per `mode`.
+fn needs_increment(remainder: &BigInt, divisor: &BigInt, quotient: &BigInt, mode: RoundingMode) -> bool {
+    if remainder.is_zero() {
+        return false;
+    }
+
+    let is_negative = remainder.sign() == Sign::Minus;
+    let remainder_abs = bigint_abs(remainder);
+
+    match mode {
+        RoundingMode::Up => true,
+        RoundingMode::Down => false,
+        RoundingMode::Ceiling => !is_negative,
+        RoundingMode::Floor => is_negative,
+        RoundingMode::HalfUp => compare_half(&remainder_abs, divisor) >= 0,
+        RoundingMode::HalfDown => compare_half(&remainder_abs, divisor) > 0,
+        RoundingMode::HalfEven => {
+            let cmp = compare_half(&remainder_abs, divisor);
+            cmp > 0 || (cmp == 0 && is_odd(quotient))
+        }
+    }
+}
+
+fn bigint_abs(n: &BigInt) -> BigInt {
+    match n.sign() {
+        Sign::Minus => -n.clone(),
+        _ => n.clone(),
+    }
+}
+
+fn is_odd(n: &BigInt) -> bool {
+    (n % 2.to_bigint().unwrap()) != BigInt::zero()
+}
+
+/// Computes `10^n` directly -- no shared cache, safe to call from any thread.
+fn big_ten_to_the(n: i32) -> BigInt {
+    if n < 0 {
+        return BigInt::zero();
+    }
+
+    10.to_bigint().unwrap().pow(n as u32)
+}
+
+/// Compares `2*a` against `b` without materializing `2*a`, mirroring Java's
+/// `BigInteger.compareHalf`. Both arguments are treated as non-negative
+/// magnitudes -- every caller here passes `remainder.abs()` and a positive
+/// divisor. Returns a value `< 0`, `== 0`, or `> 0` as `2*a` is less than,
+/// equal to, or greater than `b`.
+fn compare_half(a: &BigInt, b: &BigInt) -> i32 {
+    let mut a_val = a.to_u32_digits().1;
+    a_val.reverse();
+    let mut b_val = b.to_u32_digits().1;
+    b_val.reverse();
+
+    if a_val.is_empty() {
+        return if b_val.is_empty() { 0 } else { -1 };
+    }
+
+    if a_val.len() > b_val.len() {
+        return 1;
+    }
+
+    if a_val.len() < b_val.len() - 1 {
+        return -1;
+    }
+
+    let mut b_start = 0;
+    let mut carry = 0;
+
+    if a_val.len() != b_val.len() {
+        if b_val[b_start] == 1 {
+            b_start += 1;
+            carry = -2147483648; // sign-extended 0x80000000
+        } else {
+            return -1;
+        }
+    }
+
+    let mut a_index = 0;
+    let mut b_index = b_start;
+    let long_mask: i64 = 0xffffffff;
+    while a_index < a_val.len() {
+        let bv = b_val[b_index];
+        b_index += 1;
+        let half_bv: i64 = ((bv as i64 >> 1) + carry) & long_mask;
+        let av = a_val[a_index] as i64 & long_mask;
+        a_index += 1;
+
+        if av != half_bv {
+            return if av < half_bv { -1 } else { 1 };
+        }
+
+        carry = ((bv & 1) << 31) as i64;
+    }
+
+    if carry == 0 {
+        0
+    } else {
+        -1
+    }
+}
+
+/// Powers of ten up to `10^18`, the largest that fits in an `i64`;
+/// `big_ten_to_the` takes over for larger exponents.
+const LONG_TEN_POWERS_TABLE: [i64; 19] = [
+    1,                     // 0 / 10^0
+    10,                    // 1 / 10^1
+    100,                   // 2 / 10^2
+    1000,                  // 3 / 10^3
+    10000,                 // 4 / 10^4
+    100000,                // 5 / 10^5
+    1000000,               // 6 / 10^6
+    10000000,              // 7 / 10^7
+    100000000,             // 8 / 10^8
+    1000000000,            // 9 / 10^9
+    10000000000,           // 10 / 10^10
+    100000000000,          // 11 / 10^11
+    1000000000000,         // 12 / 10^12
+    10000000000000,        // 13 / 10^13
+    100000000000000,       // 14 / 10^14
+    1000000000000000,      // 15 / 10^15
+    10000000000000000,     // 16 / 10^16
+    100000000000000000,    // 17 / 10^17
+    1000000000000000000   // 18 / 10^18
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::rounding::*;
+    use bigdecimal::BigDecimal;
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn compare_half_test() {
+        let a = BigInt::from_str("100000000000000976996261670137755572795867919921875").unwrap();
+        let b = BigInt::from_str("1000000000000000000000000000000000000000000000000000").unwrap();
+
+        assert_eq!(-1, compare_half(&a, &b));
+    }
+
+    #[test]
+    fn round_from_str_test() {
+        let vals: Vec<(&str, i64, &str)> = vec![
+            ("1.79", 2, "1.794"),
+            ("1.8", 1, "1.75"),
+            // Regression test for a stale LONG_TEN_POWERS_TABLE[17] entry
+            // that divided by 10^16 instead of 10^17, dropping one digit
+            // too few.
+            ("0", 0, "0.12345678901234567"),
+            ("1", 0, "0.99999999999999999"),
+        ];
+
+        vals.iter().for_each(|(expected_result, scale, x)| {
+            assert_eq!(
+                Ok(BigDecimal::from_str(expected_result).unwrap()),
+                round(&BigDecimal::from_str(x).unwrap(), *scale, RoundingMode::HalfUp)
+            );
+        });
+    }
+}
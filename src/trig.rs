@@ -0,0 +1,168 @@
+//! Range-reduced sine, cosine, and tangent, built on [`crate::pi`].
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::context::Context;
+use crate::error::{BigDecimalMathError, BigDecimalMathResult};
+use crate::pi::pi;
+use crate::{get_prec, round_result, GUARD_DIGITS};
+use bigdecimal::{BigDecimal, FromPrimitive, One, ToPrimitive, Zero};
+use num_bigint::BigInt;
+// No `f64::round` in core -- `Float` supplies it via `libm` so `reduce` (and
+// therefore `sin`/`cos`/`tan`) stays available without std. Not needed under
+// `test`: the crate root's `extern crate std` brings the full `f64` inherent
+// impl back into scope.
+#[cfg(all(not(feature = "std"), not(test)))]
+use num_traits::Float;
+
+/// Computes `sin(x)` by reducing `x` into `[-π/4, π/4]` and summing the
+/// Taylor series there, where it is best conditioned.
+pub fn sin(x: &BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    let working_scale = ctx.precision.unwrap_or(get_prec(x) as u64) as i64 + GUARD_DIGITS;
+    let (reduced, quadrant) = reduce(x, ctx, working_scale)?;
+
+    let value = match quadrant {
+        0 => taylor_series(&reduced, working_scale, true),
+        1 => taylor_series(&reduced, working_scale, false),
+        2 => -taylor_series(&reduced, working_scale, true),
+        _ => -taylor_series(&reduced, working_scale, false),
+    };
+
+    round_result(value, ctx)
+}
+
+/// Computes `cos(x)` the same way as [`sin`], via the co-function identity
+/// for whichever quadrant `x` reduces into.
+pub fn cos(x: &BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    let working_scale = ctx.precision.unwrap_or(get_prec(x) as u64) as i64 + GUARD_DIGITS;
+    let (reduced, quadrant) = reduce(x, ctx, working_scale)?;
+
+    let value = match quadrant {
+        0 => taylor_series(&reduced, working_scale, false),
+        1 => -taylor_series(&reduced, working_scale, true),
+        2 => -taylor_series(&reduced, working_scale, false),
+        _ => taylor_series(&reduced, working_scale, true),
+    };
+
+    round_result(value, ctx)
+}
+
+/// Computes `tan(x) = sin(x) / cos(x)`, erroring out if `cos(x)` underflows
+/// to zero at the working precision.
+pub fn tan(x: &BigDecimal, ctx: &Context) -> BigDecimalMathResult {
+    let sin_x = sin(x, ctx)?;
+    let cos_x = cos(x, ctx)?;
+
+    if cos_x.is_zero() {
+        let msg = format!("tan is undefined at {:?}: cos underflowed to zero", x);
+        return Err(BigDecimalMathError::ArithmeticError(msg));
+    }
+
+    round_result(sin_x / cos_x, ctx)
+}
+
+/// Reduces `x` modulo `π/2`, returning the remainder (which lands in
+/// `[-π/4, π/4]`) and the quadrant (`0..4`) the original angle fell in, so
+/// the caller can pick the matching quadrant identity.
+fn reduce(x: &BigDecimal, ctx: &Context, working_scale: i64) -> Result<(BigDecimal, i64), BigDecimalMathError> {
+    let pi_ctx = Context::new(working_scale as u64, ctx.rounding);
+    let pi_val = pi(&pi_ctx)?;
+    let half_pi = &pi_val / BigDecimal::from_i64(2).unwrap();
+
+    let quotient = x / &half_pi;
+    let k = quotient.to_f64().map(|q| q.round()).ok_or_else(|| {
+        BigDecimalMathError::ArithmeticError(format!("cannot range-reduce {:?}", x))
+    })? as i64;
+
+    let reduced = (x - &half_pi * BigDecimal::from_i64(k).unwrap()).with_scale(working_scale);
+    let quadrant = k.rem_euclid(4);
+
+    Ok((reduced, quadrant))
+}
+
+/// Sums `Σ (-1)^m x^(2m+1)/(2m+1)!` (`is_sin`) or `Σ (-1)^m x^(2m)/(2m)!`
+/// until a term drops below `10^-working_scale`.
+fn taylor_series(x: &BigDecimal, working_scale: i64, is_sin: bool) -> BigDecimal {
+    let threshold = BigDecimal::new(BigInt::one(), working_scale);
+    let x_squared = (x * x).with_scale(working_scale);
+
+    let mut term = if is_sin {
+        x.clone()
+    } else {
+        BigDecimal::one()
+    };
+    term = term.with_scale(working_scale);
+
+    let mut sum = BigDecimal::zero();
+    let mut m: i64 = 0;
+
+    loop {
+        if m % 2 == 0 {
+            sum += &term;
+        } else {
+            sum -= &term;
+        }
+
+        if term.abs() < threshold {
+            break;
+        }
+
+        let (a, b) = if is_sin {
+            (2 * m + 2, 2 * m + 3)
+        } else {
+            (2 * m + 1, 2 * m + 2)
+        };
+        let denominator = BigDecimal::from_i64(a * b).unwrap();
+        term = (&term * &x_squared / &denominator).with_scale(working_scale);
+        m += 1;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rounding::RoundingMode;
+    use std::str::FromStr;
+
+    #[test]
+    fn sin_cos_of_zero() {
+        let ctx = Context::new(10, RoundingMode::HalfUp);
+
+        assert_eq!(Ok(BigDecimal::zero()), sin(&BigDecimal::zero(), &ctx));
+        assert_eq!(
+            Ok(BigDecimal::from_str("1").unwrap()),
+            cos(&BigDecimal::zero(), &ctx)
+        );
+    }
+
+    #[test]
+    fn sin_and_tan_of_one() {
+        let ctx = Context::new(15, RoundingMode::HalfUp);
+        let one = BigDecimal::from_i64(1).unwrap();
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("0.841470984807897").unwrap()),
+            sin(&one, &ctx)
+        );
+        assert_eq!(
+            Ok(BigDecimal::from_str("1.55740772465490").unwrap()),
+            tan(&one, &ctx)
+        );
+    }
+
+    #[test]
+    fn cos_of_pi_is_negative_one() {
+        use crate::pi::pi;
+
+        let ctx = Context::new(15, RoundingMode::HalfUp);
+        let pi_val = pi(&ctx).unwrap();
+
+        assert_eq!(
+            Ok(BigDecimal::from_str("-1.00000000000000").unwrap()),
+            cos(&pi_val, &ctx)
+        );
+    }
+}